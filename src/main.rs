@@ -1,22 +1,150 @@
-use chrono::{NaiveTime, Local};
+mod report;
+mod tui;
+
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use report::{CsvFormat, JsonFormat, MarkdownTableFormat, ReportFormat, ReportKind, ReportRow};
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::{self, Write};
+use std::path::Path;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 
+const SCHEDULE_FILE: &str = "schedule.json";
+/// How many days ahead the reminder worker materializes into its run-queue.
+const REMINDER_HORIZON_DAYS: i64 = 14;
+/// How long the reminder worker waits for a schedule update when its queue is empty.
+const IDLE_RECHECK: Duration = Duration::from_secs(3600);
+
+/// How often a medication rule recurs after its `start_date`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+enum Recurrence {
+    Once,
+    Daily,
+    EveryNDays(u32),
+    WeekdaysOnly,
+    EveryNHours(u32),
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct Medication {
+    name: String,
+    time: NaiveTime,
+    recurrence: Recurrence,
+    start_date: NaiveDate,
+    /// When each concrete occurrence was actually taken, keyed by "YYYY-MM-DD#slot".
+    /// Absence means the occurrence hasn't been marked taken.
+    #[serde(default)]
+    taken_at: HashMap<String, NaiveDateTime>,
+}
+
+impl Medication {
+    /// The (slot, time) pairs this rule is due at on `date`, or empty if it doesn't apply.
+    fn slots_on(&self, date: NaiveDate) -> Vec<(u32, NaiveTime)> {
+        if date < self.start_date {
+            return Vec::new();
+        }
+        match self.recurrence {
+            Recurrence::Once => {
+                if date == self.start_date {
+                    vec![(0, self.time)]
+                } else {
+                    Vec::new()
+                }
+            }
+            Recurrence::Daily => vec![(0, self.time)],
+            Recurrence::EveryNDays(n) if n > 0 => {
+                let days = (date - self.start_date).num_days();
+                if days % i64::from(n) == 0 {
+                    vec![(0, self.time)]
+                } else {
+                    Vec::new()
+                }
+            }
+            Recurrence::EveryNDays(_) => Vec::new(),
+            Recurrence::WeekdaysOnly => {
+                use chrono::Datelike;
+                match date.weekday() {
+                    chrono::Weekday::Sat | chrono::Weekday::Sun => Vec::new(),
+                    _ => vec![(0, self.time)],
+                }
+            }
+            Recurrence::EveryNHours(n) if n > 0 => {
+                let step_secs = i64::from(n) * 3600;
+                let mut secs = i64::from(self.time.num_seconds_from_midnight());
+                let mut slots = Vec::new();
+                let mut slot = 0u32;
+                while secs < 24 * 3600 {
+                    let h = (secs / 3600) as u32;
+                    let m = ((secs % 3600) / 60) as u32;
+                    let s = (secs % 60) as u32;
+                    slots.push((slot, NaiveTime::from_hms_opt(h, m, s).unwrap()));
+                    slot += 1;
+                    secs += step_secs;
+                }
+                slots
+            }
+            Recurrence::EveryNHours(_) => vec![(0, self.time)],
+        }
+    }
+
+    fn occurrence_key(date: NaiveDate, slot: u32) -> String {
+        format!("{}#{}", date.format("%Y-%m-%d"), slot)
+    }
+
+    fn taken_at(&self, date: NaiveDate, slot: u32) -> Option<NaiveDateTime> {
+        self.taken_at.get(&Self::occurrence_key(date, slot)).copied()
+    }
+
+    fn mark_taken_at(&mut self, date: NaiveDate, slot: u32, when: NaiveDateTime) {
+        self.taken_at.insert(Self::occurrence_key(date, slot), when);
+    }
+}
+
+/// A single materialized dose due on a given day, expanded from a `Medication` rule.
+#[derive(Debug, Serialize, Clone)]
+struct DueDose {
     name: String,
     time: NaiveTime,
     taken: bool,
+    taken_at: Option<NaiveDateTime>,
+}
+
+/// On-time / late / missed counts for one medication over a reporting window.
+#[derive(Debug, Default, Clone, Serialize)]
+struct AdherenceStats {
+    on_time: u32,
+    late: u32,
+    missed: u32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl AdherenceStats {
+    fn total(&self) -> u32 {
+        self.on_time + self.late + self.missed
+    }
+
+    /// Percentage of due doses (on-time or late) that were actually taken.
+    fn adherence_percent(&self) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            100.0
+        } else {
+            100.0 * f64::from(self.on_time + self.late) / f64::from(total)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AdherenceReport {
+    per_medication: HashMap<String, AdherenceStats>,
+    overall_percent: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 struct MedicationSchedule {
-    medications: HashMap<String, Vec<Medication>>, // Key: Date (YYYY-MM-DD)
+    medications: HashMap<String, Vec<Medication>>, // Key: start date (YYYY-MM-DD) the rule was added under
 }
 
 impl MedicationSchedule {
@@ -27,92 +155,404 @@ impl MedicationSchedule {
     }
 
     fn add_medication(&mut self, date: String, medication: Medication) {
-        self.medications
-            .entry(date)
-            .or_insert_with(Vec::new)
-            .push(medication);
+        self.medications.entry(date).or_default().push(medication);
     }
 
-    fn list_today(&self) -> Vec<Medication> {
-        let today = Local::now().format("%Y-%m-%d").to_string();
-        self.medications
-            .get(&today)
+    /// Every (bucket key, rule index, slot, time) due on `date`, across all rules, time-sorted.
+    fn due_entries(&self, date: NaiveDate) -> Vec<(String, usize, u32, NaiveTime)> {
+        let mut entries = Vec::new();
+        for (bucket_key, meds) in &self.medications {
+            for (rule_idx, med) in meds.iter().enumerate() {
+                for (slot, time) in med.slots_on(date) {
+                    entries.push((bucket_key.clone(), rule_idx, slot, time));
+                }
+            }
+        }
+        entries.sort_by_key(|(_, _, _, time)| *time);
+        entries
+    }
+
+    fn list_for_date(&self, date: NaiveDate) -> Vec<DueDose> {
+        self.due_entries(date)
+            .into_iter()
+            .map(|(bucket_key, rule_idx, slot, time)| {
+                let med = &self.medications[&bucket_key][rule_idx];
+                let taken_at = med.taken_at(date, slot);
+                DueDose {
+                    name: med.name.clone(),
+                    time,
+                    taken: taken_at.is_some(),
+                    taken_at,
+                }
+            })
+            .collect()
+    }
+
+    fn list_today(&self) -> Vec<DueDose> {
+        self.list_for_date(Local::now().date_naive())
+    }
+
+    /// The dose identified by `(date_key, index)`, the same addressing scheme `due_entries` and
+    /// `mark_taken` use, so the reminder worker can resolve a run-queue entry back to a `DueDose`.
+    fn dose_at(&self, date_key: &str, index: usize) -> Option<DueDose> {
+        let date = NaiveDate::parse_from_str(date_key, "%Y-%m-%d").ok()?;
+        self.list_for_date(date).into_iter().nth(index)
+    }
+
+    /// Every not-yet-taken dose due between `start` and `start + horizon_days`, keyed by the
+    /// instant it's due. The reminder worker peeks the earliest key and sleeps until it.
+    fn pending_run_queue(
+        &self,
+        start: NaiveDate,
+        horizon_days: i64,
+    ) -> BTreeMap<NaiveDateTime, Vec<(String, usize)>> {
+        let mut queue: BTreeMap<NaiveDateTime, Vec<(String, usize)>> = BTreeMap::new();
+        for offset in 0..horizon_days {
+            let date = start + chrono::Duration::days(offset);
+            let date_key = date.format("%Y-%m-%d").to_string();
+            for (index, dose) in self.list_for_date(date).into_iter().enumerate() {
+                if !dose.taken {
+                    let when = date.and_time(dose.time);
+                    queue
+                        .entry(when)
+                        .or_default()
+                        .push((date_key.clone(), index));
+                }
+            }
+        }
+        queue
+    }
+
+    fn mark_taken(&mut self, date: NaiveDate, index: usize) -> Result<(), String> {
+        let entries = self.due_entries(date);
+        let (bucket_key, rule_idx, slot, _) = entries
+            .get(index)
             .cloned()
-            .unwrap_or_else(Vec::new)
+            .ok_or_else(|| "Invalid medication index".to_string())?;
+        let med = self
+            .medications
+            .get_mut(&bucket_key)
+            .and_then(|meds| meds.get_mut(rule_idx))
+            .ok_or_else(|| "No medications found for the date".to_string())?;
+        med.mark_taken_at(date, slot, Local::now().naive_local());
+        Ok(())
     }
 
-    fn mark_taken(&mut self, date: &str, index: usize) -> Result<(), String> {
-        if let Some(meds) = self.medications.get_mut(date) {
-            if let Some(med) = meds.get_mut(index) {
-                med.taken = true;
-                Ok(())
-            } else {
-                Err("Invalid medication index".to_string())
+    /// Adherence stats per medication over `[from, to]`, using `grace_minutes` as the window
+    /// after a dose's scheduled time during which taking it still counts as on-time.
+    fn adherence_report(&self, from: NaiveDate, to: NaiveDate, grace_minutes: i64) -> AdherenceReport {
+        let now = Local::now().naive_local();
+        let grace = chrono::Duration::minutes(grace_minutes);
+        let mut per_medication: HashMap<String, AdherenceStats> = HashMap::new();
+
+        let mut date = from;
+        while date <= to {
+            for meds in self.medications.values() {
+                for med in meds {
+                    for (slot, time) in med.slots_on(date) {
+                        let scheduled = date.and_time(time);
+                        let stats = per_medication.entry(med.name.clone()).or_default();
+                        match med.taken_at(date, slot) {
+                            Some(taken_at) if taken_at <= scheduled + grace => stats.on_time += 1,
+                            Some(_) => stats.late += 1,
+                            None if scheduled + grace < now => stats.missed += 1,
+                            None => {} // still pending; too early to count as missed
+                        }
+                    }
+                }
             }
-        } else {
-            Err("No medications found for the date".to_string())
+            date += chrono::Duration::days(1);
+        }
+
+        let overall = per_medication.values().fold(AdherenceStats::default(), |mut acc, s| {
+            acc.on_time += s.on_time;
+            acc.late += s.late;
+            acc.missed += s.missed;
+            acc
+        });
+
+        AdherenceReport {
+            per_medication,
+            overall_percent: overall.adherence_percent(),
         }
     }
 
-    fn export_missed_doses(&self, file_path: &str) -> io::Result<()> {
-        let mut missed: Vec<Medication> = Vec::new();
-        for (_date, meds) in &self.medications {
-            for med in meds {
-                if !med.taken && med.time < Local::now().time() {
-                    missed.push(med.clone());
+    /// The rows a report of `kind` should contain.
+    fn report_rows(&self, kind: ReportKind) -> Vec<ReportRow> {
+        match kind {
+            ReportKind::MissedToday => {
+                let now = Local::now();
+                let date_key = now.date_naive().format("%Y-%m-%d").to_string();
+                self.list_for_date(now.date_naive())
+                    .into_iter()
+                    .filter(|dose| !dose.taken && dose.time < now.time())
+                    .map(|dose| ReportRow {
+                        date: date_key.clone(),
+                        name: dose.name,
+                        time: dose.time,
+                        taken: dose.taken,
+                        taken_at: dose.taken_at.map(|dt| dt.format("%Y-%m-%d %H:%M").to_string()),
+                    })
+                    .collect()
+            }
+            ReportKind::AllPending => {
+                let today = Local::now().date_naive();
+                let mut rows = Vec::new();
+                for offset in 0..REMINDER_HORIZON_DAYS {
+                    let date = today + chrono::Duration::days(offset);
+                    let date_key = date.format("%Y-%m-%d").to_string();
+                    for dose in self.list_for_date(date) {
+                        if !dose.taken {
+                            rows.push(ReportRow {
+                                date: date_key.clone(),
+                                name: dose.name,
+                                time: dose.time,
+                                taken: dose.taken,
+                                taken_at: dose.taken_at.map(|dt| dt.format("%Y-%m-%d %H:%M").to_string()),
+                            });
+                        }
+                    }
+                }
+                rows
+            }
+            ReportKind::FullHistory => {
+                let today = Local::now().date_naive();
+                let earliest = self
+                    .medications
+                    .values()
+                    .flatten()
+                    .map(|med| med.start_date)
+                    .min()
+                    .unwrap_or(today);
+
+                let mut rows = Vec::new();
+                let mut date = earliest;
+                while date <= today {
+                    let date_key = date.format("%Y-%m-%d").to_string();
+                    for dose in self.list_for_date(date) {
+                        rows.push(ReportRow {
+                            date: date_key.clone(),
+                            name: dose.name,
+                            time: dose.time,
+                            taken: dose.taken,
+                            taken_at: dose.taken_at.map(|dt| dt.format("%Y-%m-%d %H:%M").to_string()),
+                        });
+                    }
+                    date += chrono::Duration::days(1);
                 }
+                rows
             }
         }
-        
-        let file = File::create(file_path)?;
-        let mut wtr = csv::Writer::from_writer(file);
-        for med in missed {
-            wtr.serialize(med)?;
+    }
+
+    fn load(path: &str) -> io::Result<MedicationSchedule> {
+        if !Path::new(path).exists() {
+            return Ok(MedicationSchedule::new());
         }
-        wtr.flush()?;
-        Ok(())
+        let file = File::open(path)?;
+        serde_json::from_reader(file)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    fn save(&self, path: &str) -> io::Result<()> {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(file, self)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
     }
 }
 
-fn main() {
-    let mut schedule = MedicationSchedule::new();
+/// Renders a dose's status for the menu: "Pending" or "Taken at HH:MM".
+fn dose_status(dose: &DueDose) -> String {
+    match dose.taken_at {
+        Some(taken_at) => format!("Taken at {}", taken_at.format("%H:%M")),
+        None => "Pending".to_string(),
+    }
+}
 
-    // Channel for communication between reminder thread and main thread
-    let (tx, rx) = mpsc::channel();
+/// Maps a spelled-out hour word ("one".."twelve") to its numeric value.
+fn word_to_hour(word: &str) -> Option<u32> {
+    match word {
+        "twelve" => Some(12),
+        "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        "four" => Some(4),
+        "five" => Some(5),
+        "six" => Some(6),
+        "seven" => Some(7),
+        "eight" => Some(8),
+        "nine" => Some(9),
+        "ten" => Some(10),
+        "eleven" => Some(11),
+        _ => None,
+    }
+}
+
+/// Parses clock-time phrases `fuzzydate` doesn't understand on its own: "noon"/"midnight",
+/// "half past nine"/"quarter past nine"/"quarter to nine", and "8am"/"8:30pm"-style suffixes.
+fn parse_clock_phrase(input: &str) -> Option<NaiveTime> {
+    let lower = input.trim().to_lowercase();
 
-    // Start the reminder thread
-    thread::spawn(move || loop {
-        let now = Local::now();
-        let today = now.format("%Y-%m-%d").to_string();
-        let current_time = now.time();
+    match lower.as_str() {
+        "noon" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return NaiveTime::from_hms_opt(0, 0, 0),
+        _ => {}
+    }
 
-        // Send reminders for medications due now or earlier and not taken
-        tx.send((today.clone(), current_time)).unwrap();
+    if let Some(rest) = lower.strip_prefix("half past ") {
+        let hour = word_to_hour(rest.trim())?;
+        return NaiveTime::from_hms_opt(hour % 12, 30, 0);
+    }
+    if let Some(rest) = lower.strip_prefix("quarter past ") {
+        let hour = word_to_hour(rest.trim())?;
+        return NaiveTime::from_hms_opt(hour % 12, 15, 0);
+    }
+    if let Some(rest) = lower.strip_prefix("quarter to ") {
+        let hour = word_to_hour(rest.trim())?;
+        let prev_hour = if hour == 1 { 12 } else { hour - 1 };
+        return NaiveTime::from_hms_opt(prev_hour % 12, 45, 0);
+    }
+
+    let (digits, is_pm) = if let Some(rest) = lower.strip_suffix("am") {
+        (rest.trim(), false)
+    } else if let Some(rest) = lower.strip_suffix("pm") {
+        (rest.trim(), true)
+    } else {
+        return None;
+    };
+
+    let (hour_str, minute_str) = digits.split_once(':').unwrap_or((digits, "0"));
+    let hour: u32 = hour_str.trim().parse().ok()?;
+    let minute: u32 = minute_str.trim().parse().ok()?;
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+    let hour24 = match (hour, is_pm) {
+        (12, false) => 0,
+        (12, true) => 12,
+        (h, false) => h,
+        (h, true) => h + 12,
+    };
+    NaiveTime::from_hms_opt(hour24, minute, 0)
+}
 
-        // Check every minute
-        thread::sleep(Duration::from_secs(60));
+/// Parses a time the user typed, understanding natural language ("8am", "half past nine",
+/// "tomorrow 20:00") before falling back to strict `HH:MM`. Returns the resolved time and,
+/// if the phrase also implied a date, that date.
+fn parse_time_input(input: &str) -> Option<(NaiveTime, Option<NaiveDate>)> {
+    if let Some(time) = parse_clock_phrase(input) {
+        return Some((time, None));
+    }
+    if let Ok(parsed) = fuzzydate::parse(input) {
+        return Some((parsed.time(), Some(parsed.date())));
+    }
+    NaiveTime::parse_from_str(input, "%H:%M")
+        .ok()
+        .map(|time| (time, None))
+}
+
+/// Picks a `ReportFormat` from the export file's extension, defaulting to CSV.
+fn export_report(schedule: &MedicationSchedule, kind: ReportKind, file_path: &str) -> io::Result<()> {
+    let format: Box<dyn ReportFormat> = match Path::new(file_path).extension().and_then(|ext| ext.to_str()) {
+        Some("json") => Box::new(JsonFormat),
+        Some("md") | Some("markdown") => Box::new(MarkdownTableFormat),
+        _ => Box::new(CsvFormat),
+    };
+
+    let rows = schedule.report_rows(kind);
+    let mut file = File::create(file_path)?;
+    format.write(&rows, &mut file)
+}
+
+fn main() {
+    let mut schedule = MedicationSchedule::load(SCHEDULE_FILE).unwrap_or_else(|err| {
+        println!("Could not load existing schedule ({}), starting fresh.", err);
+        MedicationSchedule::new()
     });
 
-    loop {
-        // Check for reminders
-        if let Ok((_today, current_time)) = rx.try_recv() {
-            for med in schedule.list_today() {
-                if med.time <= current_time && !med.taken {
-                    println!(
-                        "\nReminder: It's time to take your medication: {} at {}",
-                        med.name,
-                        med.time.format("%H:%M")
-                    );
+    // schedule_tx notifies the reminder worker whenever the schedule changes (add/mark), so it
+    // can recompute its run-queue instead of polling. reminder_tx carries back one-shot reminder
+    // text for the main loop to print.
+    let (schedule_tx, schedule_rx) = mpsc::channel::<MedicationSchedule>();
+    let (reminder_tx, reminder_rx) = mpsc::channel::<String>();
+    schedule_tx.send(schedule.clone()).unwrap();
+
+    thread::spawn(move || {
+        let mut current = MedicationSchedule::new();
+        let mut queue: BTreeMap<NaiveDateTime, Vec<(String, usize)>> = BTreeMap::new();
+        // Whether the startup catch-up (which may include doses already overdue) has fired.
+        // Without this, every later schedule update would rebuild the full pending run-queue
+        // from scratch and re-fire anything still overdue-but-unmarked.
+        let mut caught_up = false;
+
+        loop {
+            let wait = match queue.keys().next() {
+                Some(&when) => (when - Local::now().naive_local())
+                    .to_std()
+                    .unwrap_or(Duration::from_secs(0)),
+                None => IDLE_RECHECK,
+            };
+
+            match schedule_rx.recv_timeout(wait) {
+                Ok(updated) => {
+                    current = updated;
+                    let rebuilt = current.pending_run_queue(Local::now().date_naive(), REMINDER_HORIZON_DAYS);
+                    queue = if caught_up {
+                        let now = Local::now().naive_local();
+                        rebuilt.into_iter().filter(|(when, _)| *when > now).collect()
+                    } else {
+                        caught_up = true;
+                        rebuilt
+                    };
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if let Some((&when, doses)) = queue.iter().next() {
+                        if when <= Local::now().naive_local() {
+                            for (date_key, index) in &doses.clone() {
+                                if let Some(dose) = current.dose_at(date_key, *index) {
+                                    let _ = reminder_tx.send(format!(
+                                        "Reminder: It's time to take your medication: {} at {}",
+                                        dose.name,
+                                        dose.time.format("%H:%M")
+                                    ));
+                                }
+                            }
+                            queue.remove(&when);
+                        }
+                    }
+
+                    // The run-queue only materializes REMINDER_HORIZON_DAYS ahead, so a
+                    // long-running recurring schedule would stop getting reminders once it
+                    // drains. Re-derive it from `current` whenever it runs dry instead of
+                    // waiting for the next schedule_tx message.
+                    if queue.is_empty() {
+                        let now = Local::now().naive_local();
+                        queue = current
+                            .pending_run_queue(now.date(), REMINDER_HORIZON_DAYS)
+                            .into_iter()
+                            .filter(|(when, _)| *when > now)
+                            .collect();
+                    }
                 }
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
             }
         }
+    });
+
+    loop {
+        // Print any reminders the worker has fired since the last iteration.
+        while let Ok(message) = reminder_rx.try_recv() {
+            println!("\n{}", message);
+        }
 
         println!("\nMedication Reminder");
         println!("1. Add a medication");
         println!("2. View today's medication schedule");
         println!("3. Mark medication as taken");
-        println!("4. Export missed doses");
-        println!("5. Exit");
+        println!("4. Export a report");
+        println!("5. View adherence report");
+        println!("6. Launch interactive dashboard");
+        println!("7. Exit");
 
         print!("Choose an option: ");
         io::stdout().flush().unwrap();
@@ -122,7 +562,7 @@ fn main() {
 
         match choice.trim() {
             "1" => {
-                let _today = Local::now().format("%Y-%m-%d").to_string();
+                let today = Local::now().date_naive();
 
                 print!("Enter medication name: ");
                 io::stdout().flush().unwrap();
@@ -130,28 +570,94 @@ fn main() {
                 io::stdin().read_line(&mut name).expect("Failed to read input");
                 let name = name.trim().to_string();
 
-                print!("Enter time (HH:MM): ");
+                print!("Enter time (e.g. '8am', 'half past nine', 'tomorrow 20:00', or HH:MM): ");
                 io::stdout().flush().unwrap();
                 let mut time_input = String::new();
                 io::stdin().read_line(&mut time_input).expect("Failed to read input");
 
-                match NaiveTime::parse_from_str(time_input.trim(), "%H:%M") {
-                    Ok(time) => {
+                match parse_time_input(time_input.trim()) {
+                    Some((time, implied_date)) => {
+                        let start_date = implied_date.unwrap_or(today);
+                        println!(
+                            "Interpreted time: {} ({})",
+                            time.format("%H:%M"),
+                            start_date.format("%Y-%m-%d")
+                        );
+                        print!("Use this time? [Y/n]: ");
+                        io::stdout().flush().unwrap();
+                        let mut confirm = String::new();
+                        io::stdin().read_line(&mut confirm).expect("Failed to read input");
+                        if confirm.trim().eq_ignore_ascii_case("n") {
+                            println!("Cancelled.");
+                            continue;
+                        }
+
+                        println!("Recurrence:");
+                        println!("  1. Once");
+                        println!("  2. Daily");
+                        println!("  3. Every N days");
+                        println!("  4. Weekdays only");
+                        println!("  5. Every N hours");
+                        print!("Choose a recurrence [1-5, default 1]: ");
+                        io::stdout().flush().unwrap();
+                        let mut recurrence_input = String::new();
+                        io::stdin()
+                            .read_line(&mut recurrence_input)
+                            .expect("Failed to read input");
+
+                        let recurrence = match recurrence_input.trim() {
+                            "2" => Recurrence::Daily,
+                            "3" => {
+                                print!("Repeat every how many days? ");
+                                io::stdout().flush().unwrap();
+                                let mut n_input = String::new();
+                                io::stdin().read_line(&mut n_input).expect("Failed to read input");
+                                match n_input.trim().parse::<u32>() {
+                                    Ok(n) if n > 0 => Recurrence::EveryNDays(n),
+                                    _ => {
+                                        println!("Invalid number, defaulting to Once.");
+                                        Recurrence::Once
+                                    }
+                                }
+                            }
+                            "4" => Recurrence::WeekdaysOnly,
+                            "5" => {
+                                print!("Repeat every how many hours? ");
+                                io::stdout().flush().unwrap();
+                                let mut n_input = String::new();
+                                io::stdin().read_line(&mut n_input).expect("Failed to read input");
+                                match n_input.trim().parse::<u32>() {
+                                    Ok(n) if n > 0 => Recurrence::EveryNHours(n),
+                                    _ => {
+                                        println!("Invalid number, defaulting to Once.");
+                                        Recurrence::Once
+                                    }
+                                }
+                            }
+                            _ => Recurrence::Once,
+                        };
+
                         let medication = Medication {
                             name,
                             time,
-                            taken: false,
+                            recurrence,
+                            start_date,
+                            taken_at: HashMap::new(),
                         };
-                        schedule.add_medication(_today, medication);
+                        let bucket_key = start_date.format("%Y-%m-%d").to_string();
+                        schedule.add_medication(bucket_key, medication);
                         println!("Medication added successfully!");
+                        if let Err(err) = schedule.save(SCHEDULE_FILE) {
+                            println!("Warning: failed to save schedule: {}", err);
+                        }
+                        let _ = schedule_tx.send(schedule.clone());
                     }
-                    Err(_) => {
-                        println!("Invalid time format. Please use HH:MM.");
+                    None => {
+                        println!("Could not understand that time. Please use HH:MM or a phrase like '8am'.");
                     }
                 }
             }
             "2" => {
-                let today = Local::now().format("%Y-%m-%d").to_string();
                 let meds = schedule.list_today();
 
                 if meds.is_empty() {
@@ -164,13 +670,13 @@ fn main() {
                             i + 1,
                             med.name,
                             med.time.format("%H:%M"),
-                            if med.taken { "Taken" } else { "Pending" }
+                            dose_status(med)
                         );
                     }
                 }
             }
             "3" => {
-                let today = Local::now().format("%Y-%m-%d").to_string();
+                let today = Local::now().date_naive();
                 let meds = schedule.list_today();
 
                 if meds.is_empty() {
@@ -185,7 +691,7 @@ fn main() {
                         i + 1,
                         med.name,
                         med.time.format("%H:%M"),
-                        if med.taken { "Taken" } else { "Pending" }
+                        dose_status(med)
                     );
                 }
 
@@ -199,8 +705,14 @@ fn main() {
 
                 match index_input.trim().parse::<usize>() {
                     Ok(index) if index > 0 && index <= meds.len() => {
-                        match schedule.mark_taken(&today, index - 1) {
-                            Ok(_) => println!("Medication marked as taken!"),
+                        match schedule.mark_taken(today, index - 1) {
+                            Ok(_) => {
+                                println!("Medication marked as taken!");
+                                if let Err(err) = schedule.save(SCHEDULE_FILE) {
+                                    println!("Warning: failed to save schedule: {}", err);
+                                }
+                                let _ = schedule_tx.send(schedule.clone());
+                            }
                             Err(err) => println!("{}", err),
                         }
                     }
@@ -208,23 +720,154 @@ fn main() {
                 }
             }
             "4" => {
-                print!("Enter file name to export missed doses (e.g., missed.csv): ");
+                println!("Report type:");
+                println!("  1. Missed doses today");
+                println!("  2. Full adherence history");
+                println!("  3. All pending doses");
+                print!("Choose a report type [1-3, default 1]: ");
+                io::stdout().flush().unwrap();
+                let mut kind_input = String::new();
+                io::stdin().read_line(&mut kind_input).expect("Failed to read input");
+                let kind = match kind_input.trim() {
+                    "2" => ReportKind::FullHistory,
+                    "3" => ReportKind::AllPending,
+                    _ => ReportKind::MissedToday,
+                };
+
+                print!("Enter file name (e.g., report.csv, report.json, report.md): ");
                 io::stdout().flush().unwrap();
                 let mut file_path = String::new();
                 io::stdin().read_line(&mut file_path).expect("Failed to read input");
 
-                match schedule.export_missed_doses(file_path.trim()) {
-                    Ok(_) => println!("Missed doses exported successfully!"),
-                    Err(err) => println!("Failed to export missed doses: {}", err),
+                match export_report(&schedule, kind, file_path.trim()) {
+                    Ok(_) => println!("Report exported successfully!"),
+                    Err(err) => println!("Failed to export report: {}", err),
                 }
             }
             "5" => {
+                print!("Look back how many days? [default 30]: ");
+                io::stdout().flush().unwrap();
+                let mut days_input = String::new();
+                io::stdin().read_line(&mut days_input).expect("Failed to read input");
+                let days_back: i64 = days_input.trim().parse().unwrap_or(30).max(1);
+
+                print!("Grace window in minutes for on-time? [default 30]: ");
+                io::stdout().flush().unwrap();
+                let mut grace_input = String::new();
+                io::stdin().read_line(&mut grace_input).expect("Failed to read input");
+                let grace_minutes: i64 = grace_input.trim().parse().unwrap_or(30).max(0);
+
+                let today = Local::now().date_naive();
+                let from = today - chrono::Duration::days(days_back - 1);
+                let report = schedule.adherence_report(from, today, grace_minutes);
+
+                println!(
+                    "\nAdherence report ({} to {}):",
+                    from.format("%Y-%m-%d"),
+                    today.format("%Y-%m-%d")
+                );
+                if report.per_medication.is_empty() {
+                    println!("No medications in this window.");
+                } else {
+                    for (name, stats) in &report.per_medication {
+                        println!(
+                            "  {}: on-time {}, late {}, missed {} ({:.0}% adherence)",
+                            name,
+                            stats.on_time,
+                            stats.late,
+                            stats.missed,
+                            stats.adherence_percent()
+                        );
+                    }
+                }
+                println!("Overall adherence: {:.0}%", report.overall_percent);
+            }
+            "6" => {
+                if let Err(err) = tui::run(&mut schedule, &schedule_tx, &reminder_rx, |s| s.save(SCHEDULE_FILE)) {
+                    println!("Dashboard exited with an error: {}", err);
+                }
+            }
+            "7" => {
                 println!("Goodbye!");
                 break;
             }
             _ => {
-                println!("Invalid choice. Please enter a number between 1 and 5.");
+                println!("Invalid choice. Please enter a number between 1 and 7.");
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn med(recurrence: Recurrence, start_date: NaiveDate) -> Medication {
+        Medication {
+            name: "Test".to_string(),
+            time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            recurrence,
+            start_date,
+            taken_at: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn every_n_days_fires_on_multiples_of_n() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let m = med(Recurrence::EveryNDays(3), start);
+
+        assert_eq!(m.slots_on(start), vec![(0, m.time)]);
+        assert_eq!(m.slots_on(start + chrono::Duration::days(3)), vec![(0, m.time)]);
+    }
+
+    #[test]
+    fn every_n_days_skips_off_days() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let m = med(Recurrence::EveryNDays(3), start);
+
+        assert!(m.slots_on(start + chrono::Duration::days(1)).is_empty());
+        assert!(m.slots_on(start + chrono::Duration::days(2)).is_empty());
+    }
+
+    #[test]
+    fn every_n_days_is_empty_before_start_date() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let m = med(Recurrence::EveryNDays(2), start);
+
+        assert!(m.slots_on(start - chrono::Duration::days(1)).is_empty());
+    }
+
+    #[test]
+    fn every_n_hours_generates_slots_across_the_day_without_wrapping() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let mut m = med(Recurrence::EveryNHours(8), start);
+        m.time = NaiveTime::from_hms_opt(6, 0, 0).unwrap();
+
+        let slots = m.slots_on(start);
+
+        assert_eq!(
+            slots,
+            vec![
+                (0, NaiveTime::from_hms_opt(6, 0, 0).unwrap()),
+                (1, NaiveTime::from_hms_opt(14, 0, 0).unwrap()),
+                (2, NaiveTime::from_hms_opt(22, 0, 0).unwrap()),
+            ]
+        );
+    }
+
+    #[test]
+    fn weekdays_only_excludes_the_weekend() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let m = med(Recurrence::WeekdaysOnly, start);
+
+        // 2026-01-03 and 2026-01-04 are a Saturday and Sunday.
+        let saturday = NaiveDate::from_ymd_opt(2026, 1, 3).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+
+        assert!(m.slots_on(saturday).is_empty());
+        assert!(m.slots_on(sunday).is_empty());
+        assert_eq!(m.slots_on(monday), vec![(0, m.time)]);
+    }
+}
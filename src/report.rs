@@ -0,0 +1,69 @@
+use chrono::NaiveTime;
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// Which slice of the schedule a report covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportKind {
+    MissedToday,
+    FullHistory,
+    AllPending,
+}
+
+/// A single row in an exported report.
+#[derive(Debug, Serialize, Clone)]
+pub struct ReportRow {
+    pub date: String,
+    pub name: String,
+    pub time: NaiveTime,
+    pub taken: bool,
+    /// When the dose was actually taken ("YYYY-MM-DD HH:MM"), if it was.
+    pub taken_at: Option<String>,
+}
+
+/// A backend that can render a set of report rows to a writer. New formats plug in by
+/// implementing this trait rather than branching inside the export code.
+pub trait ReportFormat {
+    fn write(&self, rows: &[ReportRow], w: &mut dyn Write) -> io::Result<()>;
+}
+
+pub struct CsvFormat;
+
+impl ReportFormat for CsvFormat {
+    fn write(&self, rows: &[ReportRow], w: &mut dyn Write) -> io::Result<()> {
+        let mut wtr = csv::Writer::from_writer(w);
+        for row in rows {
+            wtr.serialize(row)?;
+        }
+        wtr.flush()
+    }
+}
+
+pub struct JsonFormat;
+
+impl ReportFormat for JsonFormat {
+    fn write(&self, rows: &[ReportRow], w: &mut dyn Write) -> io::Result<()> {
+        serde_json::to_writer_pretty(w, rows).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+pub struct MarkdownTableFormat;
+
+impl ReportFormat for MarkdownTableFormat {
+    fn write(&self, rows: &[ReportRow], w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "| Date | Name | Time | Status | Taken At |")?;
+        writeln!(w, "|------|------|------|--------|----------|")?;
+        for row in rows {
+            writeln!(
+                w,
+                "| {} | {} | {} | {} | {} |",
+                row.date,
+                row.name,
+                row.time.format("%H:%M"),
+                if row.taken { "Taken" } else { "Pending" },
+                row.taken_at.as_deref().unwrap_or("-")
+            )?;
+        }
+        Ok(())
+    }
+}
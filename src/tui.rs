@@ -0,0 +1,165 @@
+use crate::MedicationSchedule;
+use chrono::Local;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, List, ListItem, Row, Table};
+use ratatui::Terminal;
+use std::io;
+use std::sync::mpsc::{Receiver, Sender};
+use std::time::Duration;
+
+/// Runs the live dashboard until the user presses `q`. Reuses `list_today`/`mark_taken` directly
+/// so marking a dose taken here behaves exactly like the text menu's option 3: `schedule_tx` lets
+/// the reminder worker recompute its run-queue, and `save` persists the change the same way the
+/// menu loop does after every mutation.
+pub fn run(
+    schedule: &mut MedicationSchedule,
+    schedule_tx: &Sender<MedicationSchedule>,
+    reminder_rx: &Receiver<String>,
+    save: impl Fn(&MedicationSchedule) -> io::Result<()>,
+) -> io::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let result = run_app(&mut terminal, schedule, schedule_tx, reminder_rx, save);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    schedule: &mut MedicationSchedule,
+    schedule_tx: &Sender<MedicationSchedule>,
+    reminder_rx: &Receiver<String>,
+    save: impl Fn(&MedicationSchedule) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut selected = 0usize;
+    let mut banners: Vec<String> = Vec::new();
+
+    loop {
+        while let Ok(message) = reminder_rx.try_recv() {
+            banners.push(message);
+            if banners.len() > 5 {
+                banners.remove(0);
+            }
+        }
+
+        let now = Local::now();
+        let doses = schedule.list_today();
+        if !doses.is_empty() && selected >= doses.len() {
+            selected = doses.len() - 1;
+        }
+
+        terminal.draw(|frame| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(5), Constraint::Length(7)])
+                .split(frame.size());
+
+            let rows: Vec<Row> = doses
+                .iter()
+                .enumerate()
+                .map(|(i, dose)| {
+                    let overdue = !dose.taken && dose.time <= now.time();
+                    let status = if let Some(taken_at) = dose.taken_at {
+                        format!("Taken at {}", taken_at.format("%H:%M"))
+                    } else if overdue {
+                        "OVERDUE".to_string()
+                    } else {
+                        "Pending".to_string()
+                    };
+                    let due_in = if dose.taken {
+                        "-".to_string()
+                    } else {
+                        let minutes = (dose.time - now.time()).num_minutes();
+                        if minutes <= 0 {
+                            "now".to_string()
+                        } else {
+                            format!("{}m", minutes)
+                        }
+                    };
+
+                    let mut style = if overdue {
+                        Style::default().fg(Color::Red)
+                    } else {
+                        Style::default()
+                    };
+                    if i == selected {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+
+                    Row::new(vec![
+                        Cell::from(dose.name.clone()),
+                        Cell::from(dose.time.format("%H:%M").to_string()),
+                        Cell::from(status),
+                        Cell::from(due_in),
+                    ])
+                    .style(style)
+                })
+                .collect();
+
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Percentage(35),
+                    Constraint::Percentage(15),
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(20),
+                ],
+            )
+            .header(Row::new(vec!["Name", "Time", "Status", "Due"]).style(Style::default().add_modifier(Modifier::BOLD)))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Today's Schedule (arrows to move, Enter to mark taken, q to quit)"),
+            );
+
+            frame.render_widget(table, chunks[0]);
+
+            let banner_items: Vec<ListItem> = banners.iter().map(|b| ListItem::new(Line::from(b.as_str()))).collect();
+            let banner_list = List::new(banner_items).block(Block::default().borders(Borders::ALL).title("Reminders"));
+            frame.render_widget(banner_list, chunks[1]);
+        })?;
+
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') => break,
+                    KeyCode::Down if !doses.is_empty() => {
+                        selected = (selected + 1) % doses.len();
+                    }
+                    KeyCode::Up if !doses.is_empty() => {
+                        selected = if selected == 0 { doses.len() - 1 } else { selected - 1 };
+                    }
+                    // mark_taken mutates the schedule, so it stays out of the guard rather than
+                    // folding into it the way clippy's collapsible_match suggests.
+                    #[allow(clippy::collapsible_match)]
+                    KeyCode::Enter | KeyCode::Char(' ') if !doses.is_empty() => {
+                        if schedule.mark_taken(now.date_naive(), selected).is_ok() {
+                            let _ = save(schedule);
+                            let _ = schedule_tx.send(schedule.clone());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    Ok(())
+}